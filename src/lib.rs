@@ -80,14 +80,19 @@ impl<C: FIRCoefs> FIRFilter<C> {
 
     /// Add a sample to the current history and calculate the convolution.
     pub fn feed(&mut self, sample: C::Sample) -> C::Sample {
-        // Store the given sample in the current history slot.
+        self.store(sample);
+        self.calc()
+    }
+
+    /// Store a sample in the current history slot without calculating the
+    /// convolution, for callers that don't need an output for every input (like the
+    /// polyphase decimator, which only calculates once every `M` samples).
+    fn store(&mut self, sample: C::Sample) {
         self.inner[self.idx] = sample;
 
         // Move to the next slot and wrap around.
         self.idx += 1;
         self.idx %= C::size();
-
-        self.calc()
     }
 
     /// Calculate the convolution of saved samples with coefficients, where the given
@@ -109,6 +114,200 @@ impl<C: FIRCoefs> FIRFilter<C> {
         let (left, right) = self.inner.split_at(self.idx);
         right.iter().chain(left.iter())
     }
+
+    /// Iterate over the history of stored samples in raw storage order, with no
+    /// guarantee on temporal ordering. This is cheaper than `history()` for uses that
+    /// don't care about order, like summing energy over the window, since it skips the
+    /// split/chain needed to present the samples oldest-to-newest.
+    #[inline]
+    pub fn history_unordered<'a>(&'a self) -> impl Iterator<Item = &'a C::Sample> {
+        self.inner.iter()
+    }
+}
+
+/// A FIR filter that exploits mirror symmetry in its coefficients to evaluate the
+/// convolution with roughly half the multiplies of `FIRFilter::calc`.
+///
+/// For a symmetric coefficient set (`coefs()[i] == coefs()[size() - i - 1]`), each pair
+/// of history samples that share a coefficient is summed once before being multiplied,
+/// rather than multiplied separately. The coefficients of `C` must actually be
+/// symmetric, which `new()` checks via `FIRCoefs::verify_symmetry`.
+///
+/// Because `(a + b) * c` isn't generally bit-identical to `a * c + b * c` under
+/// floating-point rounding, results can differ from `FIRFilter::calc` by a rounding
+/// error on the order of one ULP; this trades a little precision for the halved
+/// multiply count.
+pub struct FIRFilterSym<C: FIRCoefs> {
+    /// Underlying ring-buffer storage, reused for its `store()`/history bookkeeping.
+    inner: FIRFilter<C>,
+}
+
+impl<C: FIRCoefs> FIRFilterSym<C> {
+    /// Create a new `FIRFilterSym` with empty history.
+    ///
+    /// Panics if `C`'s coefficients aren't symmetric.
+    pub fn new() -> FIRFilterSym<C> {
+        C::verify_symmetry();
+
+        FIRFilterSym {
+            inner: FIRFilter::new(),
+        }
+    }
+
+    /// Add a sample to the current history and calculate the convolution.
+    pub fn feed(&mut self, sample: C::Sample) -> C::Sample {
+        self.inner.store(sample);
+        self.calc()
+    }
+
+    /// Calculate the convolution by folding each symmetric pair of history samples
+    /// into a single sum before multiplying by their shared coefficient.
+    fn calc(&self) -> C::Sample {
+        let (hleft, hright) = self.inner.inner.split_at(self.inner.idx);
+
+        // Oldest-to-newest and newest-to-oldest views of the same history, walked in
+        // lockstep from both ends inward, mirroring `FIRFilter::history`'s ordering.
+        let mut oldest_first = hright.iter().chain(hleft.iter());
+        let mut newest_first = hleft.iter().rev().chain(hright.iter().rev());
+
+        let coefs = C::coefs();
+        let half = C::size() / 2;
+
+        let mut sum = (0..half).fold(C::Sample::zero(), |s, i| {
+            let a = *oldest_first.next().unwrap();
+            let b = *newest_first.next().unwrap();
+            s + (a + b) * coefs[i]
+        });
+
+        // Odd-length filters have a lone center tap shared by no one else.
+        if C::size() % 2 == 1 {
+            sum = sum + *oldest_first.next().unwrap() * coefs[half];
+        }
+
+        sum
+    }
+}
+
+/// A FIR filter that decimates its output by a factor of `M`, calculating the
+/// convolution only once every `M` fed samples instead of discarding the rest.
+pub struct FIRFilterDecimate<C: FIRCoefs> {
+    /// Underlying filter, fed every sample regardless of the decimation factor.
+    inner: FIRFilter<C>,
+    /// Number of input samples per output sample.
+    factor: usize,
+    /// Number of samples stored since the last output.
+    count: usize,
+}
+
+impl<C: FIRCoefs> FIRFilterDecimate<C> {
+    /// Create a new decimating filter that produces one output sample for every
+    /// `factor` samples fed to it.
+    pub fn new(factor: usize) -> FIRFilterDecimate<C> {
+        assert!(factor > 0, "decimation factor must be nonzero");
+
+        FIRFilterDecimate {
+            inner: FIRFilter::new(),
+            factor,
+            count: 0,
+        }
+    }
+
+    /// Store a sample in the history, calculating and returning the convolution only
+    /// once every `factor` samples.
+    pub fn feed_decimate(&mut self, sample: C::Sample) -> Option<C::Sample> {
+        self.inner.store(sample);
+        self.count += 1;
+
+        if self.count < self.factor {
+            return None;
+        }
+
+        self.count = 0;
+
+        Some(self.inner.calc())
+    }
+}
+
+/// A FIR filter that interpolates its input by a factor of `L`, yielding `L` output
+/// samples per input without ever multiplying against the zeros an upsample-then-
+/// filter implementation would insert.
+///
+/// The static coefficients of `C` are partitioned at construction time into `L`
+/// polyphase branches, where coefficient `k` belongs to branch `k % L` and taps every
+/// `L`-th stored sample. `FIRFilter::calc` pairs `coefs()[0]` with the oldest sample in
+/// its window and `coefs()[size() - 1]` with the newest, so branch `k % L`, evaluated
+/// oldest-sample-first like `FIRFilter::calc`, produces output phase `L - 1 - (k % L)`;
+/// `feed_interpolate` accounts for this by walking the branches in reverse so the `L`
+/// yielded samples come out in phase order.
+///
+/// Unlike the rest of this crate, the branch split and history here are heap-allocated
+/// (`Vec`) and sized at runtime from `factor` rather than derived at compile time like
+/// `impl_fir!`'s fixed-size arrays. Statically-sized per-branch arrays would need the
+/// interpolation factor as a const generic, which isn't available on the toolchain this
+/// crate otherwise targets (it still relies on the unstable `conservative_impl_trait`
+/// feature, predating const generics); this is a deliberate, toolchain-driven deviation
+/// from doing the split at compile time, not an oversight.
+pub struct FIRFilterInterpolate<C: FIRCoefs> {
+    /// Shared input-rate sample history, sized to the longest polyphase branch.
+    history: Vec<C::Sample>,
+    /// The index of the next slot to write, which holds the oldest stored sample.
+    idx: usize,
+    /// Per-branch coefficient subsets, where `branches[b][m]` is `C::coefs()[b + m * L]`.
+    /// The number of branches, `branches.len()`, is the interpolation factor `L`.
+    branches: Vec<Vec<f32>>,
+}
+
+impl<C: FIRCoefs> FIRFilterInterpolate<C> {
+    /// Create a new interpolating filter that produces `factor` output samples for
+    /// every sample fed to it.
+    ///
+    /// `C::size()` must be a multiple of `factor` so every polyphase branch has the
+    /// same tap count; this is standard practice when designing a prototype filter for
+    /// polyphase interpolation, and keeps all branches aligned to the same history.
+    pub fn new(factor: usize) -> FIRFilterInterpolate<C> {
+        assert!(factor > 0, "interpolation factor must be nonzero");
+        assert_eq!(C::size() % factor, 0,
+                   "coefficient count must be a multiple of the interpolation factor");
+
+        let mut branches: Vec<Vec<f32>> = (0..factor).map(|_| Vec::new()).collect();
+
+        for (k, &c) in C::coefs().iter().enumerate() {
+            branches[k % factor].push(c);
+        }
+
+        let len = branches.iter().map(|b| b.len()).max().unwrap_or(0);
+
+        FIRFilterInterpolate {
+            history: vec![C::Sample::zero(); len],
+            idx: 0,
+            branches,
+        }
+    }
+
+    /// Store a sample in the history and yield the `factor` interpolated output
+    /// samples, in phase order.
+    pub fn feed_interpolate<'a>(&'a mut self, sample: C::Sample) -> impl Iterator<Item = C::Sample> + 'a {
+        let len = self.history.len();
+
+        self.history[self.idx] = sample;
+
+        self.idx += 1;
+        self.idx %= len;
+
+        let this = &*self;
+        this.branches.iter().rev().map(move |branch| this.calc_branch(branch))
+    }
+
+    /// Convolve a single polyphase branch's coefficients against the shared history,
+    /// pairing coefficient `m` with the `m`-th oldest stored sample, matching
+    /// `FIRFilter::calc`'s convention.
+    fn calc_branch(&self, branch: &[f32]) -> C::Sample {
+        let (left, right) = self.history.split_at(self.idx);
+        let oldest_first = right.iter().chain(left.iter());
+
+        branch.iter().zip(oldest_first)
+            .fold(C::Sample::zero(), |s, (&c, &x)| s + x * c)
+    }
 }
 
 #[cfg(test)]
@@ -156,6 +355,16 @@ mod test {
         0.2,
     ]);
 
+    impl_fir!(SymmetricOddFIR7, f32, 7, [
+        0.1,
+        0.2,
+        0.3,
+        0.4,
+        0.3,
+        0.2,
+        0.1,
+    ]);
+
     #[test]
     fn test_fir() {
         let mut f = FIRFilter::<TestFIR>::new();
@@ -182,12 +391,105 @@ mod test {
         assert_eq!(iter.next().unwrap(), &400.0);
     }
 
+    #[test]
+    fn test_history_unordered() {
+        let mut f = FIRFilter::<TestFIR>::new();
+
+        f.feed(1.0);
+        f.feed(2.0);
+        f.feed(3.0);
+        f.feed(4.0);
+        f.feed(5.0);
+
+        // After 5 feeds into a 4-slot history, slot 0 has been overwritten by the
+        // 5th sample, so raw storage order is [5.0, 2.0, 3.0, 4.0].
+        let raw: Vec<_> = f.history_unordered().cloned().collect();
+        assert_eq!(raw, vec![5.0, 2.0, 3.0, 4.0]);
+
+        let ordered: Vec<_> = f.history().cloned().collect();
+        assert_eq!(ordered, vec![2.0, 3.0, 4.0, 5.0]);
+    }
+
     #[test]
     fn test_verify_symmetry() {
         SymmetricOddFIR::verify_symmetry();
         SymmetricEvenFIR::verify_symmetry();
     }
 
+    /// `FIRFilterSym::calc` reassociates the sum as `(a + b) * c` rather than
+    /// `a * c + b * c`, which isn't generally bit-identical under floating-point
+    /// rounding, so comparisons against `FIRFilter::calc` use an epsilon rather than
+    /// exact equality.
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} and {} differ by more than epsilon", a, b);
+    }
+
+    #[test]
+    fn test_fir_sym_close_to_fir() {
+        let samples = [100.0, 200.0, 300.0, 400.0, 0.0, 0.0, 50.0, 0.0, 0.0, 0.0];
+
+        let mut f = FIRFilter::<SymmetricOddFIR>::new();
+        let mut fs = FIRFilterSym::<SymmetricOddFIR>::new();
+
+        for &x in samples.iter() {
+            assert_close(f.feed(x), fs.feed(x));
+        }
+
+        let mut f = FIRFilter::<SymmetricEvenFIR>::new();
+        let mut fs = FIRFilterSym::<SymmetricEvenFIR>::new();
+
+        for &x in samples.iter() {
+            assert_close(f.feed(x), fs.feed(x));
+        }
+
+        // Non-round-number coefficients and samples, which is where `(a + b) * c`
+        // and `a * c + b * c` actually diverge by a rounding ULP.
+        let samples = [1.1, 2.2, 3.3, 4.4, 5.5, 6.6, 7.7];
+
+        let mut f = FIRFilter::<SymmetricOddFIR7>::new();
+        let mut fs = FIRFilterSym::<SymmetricOddFIR7>::new();
+
+        for &x in samples.iter() {
+            assert_close(f.feed(x), fs.feed(x));
+        }
+    }
+
+    #[test]
+    fn test_fir_decimate() {
+        let mut f = FIRFilterDecimate::<TestFIR>::new(2);
+
+        let inputs = [
+            100.0, 200.0, 300.0, 400.0, 0.0, 0.0, 0.0, 0.0, 0.0, 100.0, 200.0, 300.0,
+            400.0,
+        ];
+
+        let mut outputs = vec![];
+
+        for &x in inputs.iter() {
+            if let Some(y) = f.feed_decimate(x) {
+                outputs.push(y);
+            }
+        }
+
+        // One output for every 2 inputs, matching FIRFilter::feed at those steps.
+        assert_eq!(outputs, vec![200.0, 700.0, 300.0, 0.0, 0.0, 400.0]);
+    }
+
+    #[test]
+    fn test_fir_interpolate() {
+        let mut f = FIRFilterInterpolate::<TestFIR>::new(2);
+
+        let mut outputs = vec![];
+
+        for &x in [100.0, 200.0, 300.0].iter() {
+            outputs.extend(f.feed_interpolate(x));
+        }
+
+        // Equivalent to zero-stuffing each input with 1 zero and running it through
+        // FIRFilter::feed: [100,0, 200,0, 300,0] -> [0,200, 0,500, 0,800].
+        assert_eq!(outputs, vec![0.0, 200.0, 0.0, 500.0, 0.0, 800.0]);
+    }
+
     #[test]
     #[should_panic]
     fn test_verify_nonsymmetry_odd() {